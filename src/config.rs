@@ -0,0 +1,84 @@
+use serde::Deserialize;
+use std::fs;
+
+/// Prefix shared by every vault under iCloud's Obsidian sync folder. Not
+/// user-configurable — only the vault name and the doc sub-path are.
+const ICLOUD_OBSIDIAN_BASE: &str = "Library/Mobile Documents/iCloud~md~obsidian/Documents";
+
+/// User-editable settings loaded from `~/.config/claude-tui/config.toml`.
+/// Falls back to the built-in defaults (matching the tool's previous
+/// hardcoded behavior) when the file is absent or fails to parse.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub scan_dirs: Vec<String>,
+    pub obsidian: ObsidianConfig,
+    pub keys: KeyBindings,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ObsidianConfig {
+    pub vault: String,
+    pub doc_path: String,
+}
+
+/// Single-character key remaps for the main actions. `launch` is additional
+/// to the hardcoded Enter key rather than a replacement for it.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    pub launch: Option<char>,
+    pub finder: char,
+    pub docs: char,
+    pub search: char,
+    pub quit: char,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            scan_dirs: vec!["Documents/app".to_string(), "Documents/playground".to_string()],
+            obsidian: ObsidianConfig::default(),
+            keys: KeyBindings::default(),
+        }
+    }
+}
+
+impl Default for ObsidianConfig {
+    fn default() -> Self {
+        Self {
+            vault: "NV".to_string(),
+            doc_path: "Personal/App".to_string(),
+        }
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            launch: None,
+            finder: 'f',
+            docs: 'd',
+            search: '/',
+            quit: 'q',
+        }
+    }
+}
+
+impl Config {
+    /// Load `~/.config/claude-tui/config.toml`, falling back to defaults
+    /// when the file is missing or malformed.
+    pub fn load() -> Self {
+        let Some(home) = dirs::home_dir() else { return Self::default() };
+        let path = home.join(".config/claude-tui/config.toml");
+        let Ok(text) = fs::read_to_string(&path) else { return Self::default() };
+        toml::from_str(&text).unwrap_or_default()
+    }
+
+    /// Path of the Obsidian docs folder for `obsidian.vault` / `obsidian.doc_path`,
+    /// relative to the home directory.
+    pub fn obsidian_docs_path(&self) -> String {
+        format!("{}/{}/{}", ICLOUD_OBSIDIAN_BASE, self.obsidian.vault, self.obsidian.doc_path)
+    }
+}
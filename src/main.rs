@@ -10,23 +10,92 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph},
     Frame, Terminal,
 };
+use notify::{recommended_watcher, Event as FsEvent, RecommendedWatcher, RecursiveMode, Watcher};
+use syntect::easy::HighlightLines;
+use syntect::highlighting::ThemeSet;
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
 use std::{
+    collections::HashSet,
     fs,
     io::{self, stdout},
-    path::PathBuf,
+    path::{Path, PathBuf},
     process::Command,
-    time::SystemTime,
+    sync::mpsc::{channel, Receiver, Sender},
+    sync::{Arc, Mutex, OnceLock},
+    thread,
+    time::{Duration, Instant, SystemTime},
 };
 
-const SCAN_DIRS: &[&str] = &["Documents/app", "Documents/playground"];
-const OBSIDIAN_DOCS: &str = "Library/Mobile Documents/iCloud~md~obsidian/Documents/NV/Personal/App";
+mod config;
+use config::Config;
+
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(200);
 
 struct App {
+    config: Config,
     projects: Vec<Project>,
     list_state: ListState,
     searching: bool,
     filter: String,
     quit: bool,
+    // Kept alive for the lifetime of the app; dropping it stops the watch.
+    // `None` if the watcher itself failed to start — the app still runs,
+    // just without live refresh. A project directory that could not be
+    // watched is skipped and gets no live refresh.
+    _watcher: Option<RecommendedWatcher>,
+    watch_rx: Option<Receiver<PathBuf>>,
+    preview_visible: bool,
+    preview_scroll: u16,
+    preview_cache: Option<PreviewCache>,
+    sort_mode: SortMode,
+    probe_rx: Receiver<ProbeResult>,
+    pending_count: usize,
+    start_time: Instant,
+    detail_tx: Sender<DetailResult>,
+    detail_rx: Receiver<DetailResult>,
+}
+
+const SPINNER_FRAMES: &[char] = &['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const SPINNER_FRAME_MS: u128 = 80;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SortMode {
+    Recent,
+    Name,
+    Source,
+    Dirty,
+}
+
+impl SortMode {
+    fn next(self) -> Self {
+        match self {
+            SortMode::Recent => SortMode::Name,
+            SortMode::Name => SortMode::Source,
+            SortMode::Source => SortMode::Dirty,
+            SortMode::Dirty => SortMode::Recent,
+        }
+    }
+
+    /// Label shown in the header, including the mode's natural direction.
+    fn indicator(self) -> &'static str {
+        match self {
+            SortMode::Recent => "recent ↓",
+            SortMode::Name => "name ↑",
+            SortMode::Source => "source ↑",
+            SortMode::Dirty => "dirty ↓",
+        }
+    }
+}
+
+/// Highlighted preview text for the currently selected project, keyed by
+/// path + mtime so the same file is not re-highlighted until it changes.
+/// Holds a single entry: selecting a project with a different preview file
+/// replaces it, and selecting the earlier project again re-highlights that file.
+struct PreviewCache {
+    path: PathBuf,
+    mtime: Option<SystemTime>,
+    lines: Vec<Line<'static>>,
 }
 
 struct Project {
@@ -38,6 +107,56 @@ struct Project {
     git_branch: Option<String>,
     git_dirty: bool,
     config_labels: Vec<String>,
+    detail: Option<ProjectDetail>,
+    // True until its background probe task reports in; drives the spinner.
+    pending: bool,
+    // True while a background `compute_project_detail` task for this project
+    // is in flight, so `ensure_detail_cache` doesn't spawn a duplicate.
+    detail_pending: bool,
+}
+
+/// Expensive per-project stats for the detail footer: computed only for the
+/// selected project and cached on it, since a `git log`/`rev-list` call and
+/// a full directory walk per row would be too slow to do for every project
+/// on every scan.
+struct ProjectDetail {
+    absolute_path: String,
+    last_commit_subject: Option<String>,
+    ahead_behind: Option<(u64, u64)>,
+    dirty_count: usize,
+    total_size: u64,
+}
+
+/// Case-insensitive natural-order comparison: digit runs compare by numeric
+/// value rather than lexically, so "item2" sorts before "item10".
+fn natural_cmp(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    let mut ai = a.chars().peekable();
+    let mut bi = b.chars().peekable();
+    loop {
+        match (ai.peek(), bi.peek()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(&ca), Some(&cb)) if ca.is_ascii_digit() && cb.is_ascii_digit() => {
+                let na: String = std::iter::from_fn(|| ai.next_if(|c| c.is_ascii_digit())).collect();
+                let nb: String = std::iter::from_fn(|| bi.next_if(|c| c.is_ascii_digit())).collect();
+                match na.parse::<u64>().unwrap_or(0).cmp(&nb.parse::<u64>().unwrap_or(0)) {
+                    Ordering::Equal => continue,
+                    other => return other,
+                }
+            }
+            (Some(&ca), Some(&cb)) => {
+                let (la, lb) = (ca.to_ascii_lowercase(), cb.to_ascii_lowercase());
+                if la != lb {
+                    return la.cmp(&lb);
+                }
+                ai.next();
+                bi.next();
+            }
+        }
+    }
 }
 
 /// Normalize a name for fuzzy matching: lowercase, strip hyphens/spaces/underscores
@@ -46,11 +165,111 @@ fn normalize(name: &str) -> String {
         .replace(['-', '_', ' '], "")
 }
 
+/// Percent-encode `s` for embedding in a URI, per RFC 3986: every byte
+/// outside the unreserved set (letters, digits, `-_.~`) becomes `%XX`.
+/// Used for config-supplied values (vault name, doc path) that may contain
+/// spaces, `&`, `#`, or non-ASCII characters.
+fn percent_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(byte as char)
+            }
+            _ => out.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    out
+}
+
+const FUZZY_MATCH_BASE: i32 = 16;
+const FUZZY_BOUNDARY_BONUS: i32 = 8;
+const FUZZY_CONSECUTIVE_BONUS: i32 = 8;
+const FUZZY_GAP_PENALTY_CAP: i32 = 6;
+
+/// Result of matching `query` as a subsequence of a candidate string: the
+/// accumulated score and the byte ranges of the matched characters (for
+/// highlighting in the UI).
+struct FuzzyMatch {
+    score: i32,
+    ranges: Vec<(usize, usize)>,
+}
+
+/// Subsequence fuzzy match: every character of `query` must appear in
+/// `candidate`, in order, though not necessarily contiguously. Scores
+/// matches at word boundaries and consecutive runs higher, and penalizes
+/// gaps, so e.g. "ddg" scores higher against "daily-digest" than a random
+/// scattering of the same letters would.
+fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    if query.is_empty() {
+        return Some(FuzzyMatch { score: 0, ranges: Vec::new() });
+    }
+
+    let query_lower: Vec<char> = query.to_lowercase().chars().collect();
+    let cand_chars: Vec<(usize, char)> = candidate.char_indices().collect();
+    let cand_lower: Vec<char> = candidate.to_lowercase().chars().collect();
+    if cand_lower.len() != cand_chars.len() {
+        // Lowercasing changed the char count (rare, non-ASCII edge case) —
+        // fall back to treating it as unmatchable rather than misaligning
+        // byte ranges.
+        return None;
+    }
+
+    let mut qi = 0;
+    let mut score = 0i32;
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    let mut prev_match: Option<usize> = None;
+
+    for (ci, &lc) in cand_lower.iter().enumerate() {
+        if qi >= query_lower.len() {
+            break;
+        }
+        if lc != query_lower[qi] {
+            continue;
+        }
+
+        let mut char_score = FUZZY_MATCH_BASE;
+
+        let at_boundary = ci == 0
+            || matches!(cand_chars[ci - 1].1, '-' | '_' | ' ')
+            || (cand_chars[ci - 1].1.is_lowercase() && cand_chars[ci].1.is_uppercase());
+        if at_boundary {
+            char_score += FUZZY_BOUNDARY_BONUS;
+        }
+
+        match prev_match {
+            Some(prev) if ci == prev + 1 => char_score += FUZZY_CONSECUTIVE_BONUS,
+            Some(prev) => {
+                let gap = (ci - prev - 1) as i32;
+                char_score -= gap.min(FUZZY_GAP_PENALTY_CAP);
+            }
+            None => {}
+        }
+
+        score += char_score;
+
+        let (byte_start, ch) = cand_chars[ci];
+        let byte_end = byte_start + ch.len_utf8();
+        match ranges.last_mut() {
+            Some(last) if last.1 == byte_start => last.1 = byte_end,
+            _ => ranges.push((byte_start, byte_end)),
+        }
+
+        prev_match = Some(ci);
+        qi += 1;
+    }
+
+    if qi < query_lower.len() {
+        return None;
+    }
+    Some(FuzzyMatch { score, ranges })
+}
+
 /// Find the matching Obsidian doc for a project by fuzzy name matching.
 /// e.g. project "daily-digest" matches doc "Daily Digest.md"
-fn find_obsidian_doc(project_name: &str) -> Option<PathBuf> {
+fn find_obsidian_doc(project_name: &str, config: &Config) -> Option<PathBuf> {
     let home = dirs::home_dir()?;
-    let docs_dir = home.join(OBSIDIAN_DOCS);
+    let docs_dir = home.join(config.obsidian_docs_path());
     let normalized_project = normalize(project_name);
 
     let entries = fs::read_dir(&docs_dir).ok()?;
@@ -65,6 +284,64 @@ fn find_obsidian_doc(project_name: &str) -> Option<PathBuf> {
     None
 }
 
+/// Pick the file to show in the preview pane for a project: `CLAUDE.md`
+/// takes priority, then `README.md`, then a matched Obsidian doc.
+fn find_preview_path(project: &Project, config: &Config) -> Option<PathBuf> {
+    let claude_md = project.path.join("CLAUDE.md");
+    if claude_md.exists() {
+        return Some(claude_md);
+    }
+    let readme = project.path.join("README.md");
+    if readme.exists() {
+        return Some(readme);
+    }
+    find_obsidian_doc(&project.name, config)
+}
+
+/// Syntect's default syntax/theme tables, loaded from their bincode dumps
+/// once and reused for every preview render. Deserializing them fresh per
+/// project switch would stall the UI for exactly the reason this cache exists.
+static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+static THEME_SET: OnceLock<ThemeSet> = OnceLock::new();
+
+/// Syntax-highlight a Markdown file into ratatui `Line`s using `syntect`.
+fn render_preview(path: &Path) -> Vec<Line<'static>> {
+    let Ok(text) = fs::read_to_string(path) else {
+        return vec![Line::from(Span::styled(
+            "(could not read file)",
+            Style::default().fg(Color::DarkGray),
+        ))];
+    };
+
+    let syntax_set = SYNTAX_SET.get_or_init(SyntaxSet::load_defaults_newlines);
+    let theme_set = THEME_SET.get_or_init(ThemeSet::load_defaults);
+    let syntax = syntax_set
+        .find_syntax_by_extension("md")
+        .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+    let theme = &theme_set.themes["base16-ocean.dark"];
+    let mut highlighter = HighlightLines::new(syntax, theme);
+
+    LinesWithEndings::from(&text)
+        .map(|line| {
+            let ranges = highlighter
+                .highlight_line(line, syntax_set)
+                .unwrap_or_default();
+            let spans: Vec<Span<'static>> = ranges
+                .into_iter()
+                .map(|(style, text)| {
+                    let color = Color::Rgb(
+                        style.foreground.r,
+                        style.foreground.g,
+                        style.foreground.b,
+                    );
+                    Span::styled(text.trim_end_matches('\n').to_string(), Style::default().fg(color))
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
+
 fn format_relative_time(time: Option<SystemTime>) -> String {
     let Some(t) = time else { return "—".to_string() };
     let Ok(elapsed) = t.elapsed() else { return "—".to_string() };
@@ -79,11 +356,212 @@ fn format_relative_time(time: Option<SystemTime>) -> String {
     }
 }
 
-fn scan_projects() -> Vec<Project> {
+/// Probe a project directory for the metadata that can change after the
+/// initial scan: git branch/dirty state, last-modified time, and Claude
+/// config labels. Shared by `spawn_probe_tasks` (startup probe of every
+/// project) and `App::refresh_project` (targeted re-probe triggered by the
+/// watcher).
+fn probe_project(path: &Path, is_git: bool) -> (Option<String>, bool, Option<SystemTime>, Vec<String>) {
+    // Git info
+    let (git_branch, git_dirty) = if is_git {
+        let branch = Command::new("git")
+            .args(["-C", &path.to_string_lossy(), "branch", "--show-current"])
+            .output()
+            .ok()
+            .and_then(|o| {
+                let s = String::from_utf8_lossy(&o.stdout).trim().to_string();
+                if s.is_empty() { None } else { Some(s) }
+            });
+        let dirty = Command::new("git")
+            .args(["-C", &path.to_string_lossy(), "status", "--porcelain"])
+            .output()
+            .map(|o| !o.stdout.is_empty())
+            .unwrap_or(false);
+        (branch, dirty)
+    } else {
+        (None, false)
+    };
+
+    // Modified time: git log for repos, smart mtime for non-git
+    let modified = if is_git {
+        Command::new("git")
+            .args(["-C", &path.to_string_lossy(), "log", "-1", "--format=%ct"])
+            .output()
+            .ok()
+            .and_then(|o| {
+                let s = String::from_utf8_lossy(&o.stdout).trim().to_string();
+                s.parse::<u64>().ok()
+            })
+            .map(|ts| SystemTime::UNIX_EPOCH + Duration::from_secs(ts))
+    } else {
+        // Scan direct children, skip .DS_Store and hidden files
+        fs::read_dir(path).ok().and_then(|entries| {
+            entries.flatten()
+                .filter(|e| {
+                    let name = e.file_name().to_string_lossy().to_string();
+                    !name.starts_with('.') && name != ".DS_Store"
+                })
+                .filter_map(|e| e.metadata().ok()?.modified().ok())
+                .max()
+        })
+    };
+
+    // Claude config labels
+    let mut config_labels = Vec::new();
+    if path.join("CLAUDE.md").exists() {
+        config_labels.push("claude.md".to_string());
+    }
+    let skill_count = path.join(".claude/commands").read_dir()
+        .map(|d| d.flatten().count())
+        .unwrap_or(0);
+    if skill_count > 0 {
+        config_labels.push(format!("{}skills", skill_count));
+    }
+    if path.join(".mcp.json").exists() {
+        let mcp_count = fs::read_to_string(path.join(".mcp.json"))
+            .ok()
+            .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+            .and_then(|v| v.get("mcpServers").and_then(|m| m.as_object()).map(|o| o.len()))
+            .unwrap_or(1);
+        config_labels.push(format!("{}mcp", mcp_count));
+    }
+
+    (git_branch, git_dirty, modified, config_labels)
+}
+
+/// Total size in bytes of `path`'s contents, skipping `.git` and other
+/// hidden directories/files.
+fn dir_size(path: &Path) -> u64 {
+    let mut total = 0u64;
+    if let Ok(entries) = fs::read_dir(path) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with('.') {
+                continue;
+            }
+            let Ok(metadata) = entry.metadata() else { continue };
+            if metadata.is_dir() {
+                total += dir_size(&entry.path());
+            } else {
+                total += metadata.len();
+            }
+        }
+    }
+    total
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[unit])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Compute the expensive detail-footer stats for one project: last commit
+/// subject, ahead/behind counts vs its upstream, uncommitted file count,
+/// and total on-disk size.
+fn compute_project_detail(path: &Path, is_git: bool) -> ProjectDetail {
+    let absolute_path = path.to_string_lossy().to_string();
+
+    let last_commit_subject = if is_git {
+        Command::new("git")
+            .args(["-C", &absolute_path, "log", "-1", "--format=%s"])
+            .output()
+            .ok()
+            .and_then(|o| {
+                let s = String::from_utf8_lossy(&o.stdout).trim().to_string();
+                if s.is_empty() { None } else { Some(s) }
+            })
+    } else {
+        None
+    };
+
+    let ahead_behind = if is_git {
+        Command::new("git")
+            .args(["-C", &absolute_path, "rev-list", "--left-right", "--count", "@{u}...HEAD"])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .and_then(|o| {
+                let s = String::from_utf8_lossy(&o.stdout).trim().to_string();
+                let mut parts = s.split_whitespace();
+                let behind: u64 = parts.next()?.parse().ok()?;
+                let ahead: u64 = parts.next()?.parse().ok()?;
+                Some((ahead, behind))
+            })
+    } else {
+        None
+    };
+
+    let dirty_count = if is_git {
+        Command::new("git")
+            .args(["-C", &absolute_path, "status", "--porcelain"])
+            .output()
+            .map(|o| String::from_utf8_lossy(&o.stdout).lines().count())
+            .unwrap_or(0)
+    } else {
+        0
+    };
+
+    let total_size = dir_size(path);
+
+    ProjectDetail {
+        absolute_path,
+        last_commit_subject,
+        ahead_behind,
+        dirty_count,
+        total_size,
+    }
+}
+
+/// Result of a background `compute_project_detail` task, reported back over
+/// a channel once the git/size work for one project's detail footer completes.
+struct DetailResult {
+    path: PathBuf,
+    detail: ProjectDetail,
+}
+
+/// Spawn a worker thread to compute one project's detail-footer stats off
+/// the render path, reporting the result back over `tx`. Mirrors
+/// `spawn_probe_tasks`'s one-thread-per-job pattern, but on demand (when the
+/// project is first selected) rather than all at once at startup.
+fn spawn_detail_task(path: PathBuf, tx: Sender<DetailResult>) {
+    thread::spawn(move || {
+        let is_git = path.join(".git").exists();
+        let detail = compute_project_detail(&path, is_git);
+        let _ = tx.send(DetailResult { path, detail });
+    });
+}
+
+/// Result of a background `probe_project` task for one project, reported
+/// back over a channel as it completes.
+struct ProbeResult {
+    path: PathBuf,
+    git_branch: Option<String>,
+    git_dirty: bool,
+    modified: Option<SystemTime>,
+    config_labels: Vec<String>,
+    has_doc: bool,
+}
+
+/// Scan for projects using only cheap `fs::read_dir` calls, so the list
+/// renders instantly. Git branch/dirty state, modified time, config labels,
+/// and whether a matching Obsidian doc exists are all left blank and filled
+/// in later by `spawn_probe_tasks` — matching a doc name means reading the
+/// whole vault directory, which is too slow to do per project up front.
+fn scan_projects(config: &Config) -> Vec<Project> {
     let home = dirs::home_dir().expect("Cannot find home directory");
     let mut projects = Vec::new();
 
-    for dir in SCAN_DIRS {
+    for dir in &config.scan_dirs {
         let full_path = home.join(dir);
         let source = dir.rsplit('/').next().unwrap_or(dir);
 
@@ -93,82 +571,18 @@ fn scan_projects() -> Vec<Project> {
                 if path.is_dir() {
                     let name = entry.file_name().to_string_lossy().to_string();
                     if !name.starts_with('.') && name != "claude-tui" {
-                        let has_doc = find_obsidian_doc(&name).is_some();
-                        let is_git = path.join(".git").exists();
-
-                        // Git info
-                        let (git_branch, git_dirty) = if is_git {
-                            let branch = Command::new("git")
-                                .args(["-C", &path.to_string_lossy(), "branch", "--show-current"])
-                                .output()
-                                .ok()
-                                .and_then(|o| {
-                                    let s = String::from_utf8_lossy(&o.stdout).trim().to_string();
-                                    if s.is_empty() { None } else { Some(s) }
-                                });
-                            let dirty = Command::new("git")
-                                .args(["-C", &path.to_string_lossy(), "status", "--porcelain"])
-                                .output()
-                                .map(|o| !o.stdout.is_empty())
-                                .unwrap_or(false);
-                            (branch, dirty)
-                        } else {
-                            (None, false)
-                        };
-
-                        // Modified time: git log for repos, smart mtime for non-git
-                        let modified = if is_git {
-                            Command::new("git")
-                                .args(["-C", &path.to_string_lossy(), "log", "-1", "--format=%ct"])
-                                .output()
-                                .ok()
-                                .and_then(|o| {
-                                    let s = String::from_utf8_lossy(&o.stdout).trim().to_string();
-                                    s.parse::<u64>().ok()
-                                })
-                                .map(|ts| SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(ts))
-                        } else {
-                            // Scan direct children, skip .DS_Store and hidden files
-                            fs::read_dir(&path).ok().and_then(|entries| {
-                                entries.flatten()
-                                    .filter(|e| {
-                                        let name = e.file_name().to_string_lossy().to_string();
-                                        !name.starts_with('.') && name != ".DS_Store"
-                                    })
-                                    .filter_map(|e| e.metadata().ok()?.modified().ok())
-                                    .max()
-                            })
-                        };
-
-                        // Claude config labels
-                        let mut config_labels = Vec::new();
-                        if path.join("CLAUDE.md").exists() {
-                            config_labels.push("claude.md".to_string());
-                        }
-                        let skill_count = path.join(".claude/commands").read_dir()
-                            .map(|d| d.flatten().count())
-                            .unwrap_or(0);
-                        if skill_count > 0 {
-                            config_labels.push(format!("{}skills", skill_count));
-                        }
-                        if path.join(".mcp.json").exists() {
-                            let mcp_count = fs::read_to_string(path.join(".mcp.json"))
-                                .ok()
-                                .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
-                                .and_then(|v| v.get("mcpServers").and_then(|m| m.as_object()).map(|o| o.len()))
-                                .unwrap_or(1);
-                            config_labels.push(format!("{}mcp", mcp_count));
-                        }
-
                         projects.push(Project {
                             name,
                             path,
                             source: source.to_string(),
-                            modified,
-                            has_doc,
-                            git_branch,
-                            git_dirty,
-                            config_labels,
+                            modified: None,
+                            has_doc: false,
+                            git_branch: None,
+                            git_dirty: false,
+                            config_labels: Vec::new(),
+                            detail: None,
+                            pending: true,
+                            detail_pending: false,
                         });
                     }
                 }
@@ -176,35 +590,262 @@ fn scan_projects() -> Vec<Project> {
         }
     }
 
-    // Sort by most recently modified first
-    projects.sort_by(|a, b| b.modified.cmp(&a.modified));
     projects
 }
 
+/// Spawn one worker thread per project to run the blocking git/mtime/config
+/// probe and the Obsidian doc lookup, reporting results back over a channel
+/// as each completes. Lets the first frame render immediately instead of
+/// blocking on every repo's `git` subprocess calls and vault scan up front.
+fn spawn_probe_tasks(projects: &[Project], config: Config) -> Receiver<ProbeResult> {
+    let config = Arc::new(config);
+    let (tx, rx) = channel();
+    for project in projects {
+        let tx = tx.clone();
+        let path = project.path.clone();
+        let name = project.name.clone();
+        let config = Arc::clone(&config);
+        thread::spawn(move || {
+            let is_git = path.join(".git").exists();
+            let (git_branch, git_dirty, modified, config_labels) = probe_project(&path, is_git);
+            let has_doc = find_obsidian_doc(&name, &config).is_some();
+            let _ = tx.send(ProbeResult {
+                path,
+                git_branch,
+                git_dirty,
+                modified,
+                config_labels,
+                has_doc,
+            });
+        });
+    }
+    rx
+}
+
+/// True for any path under a `.git/objects` directory, the one subtree that
+/// churns on every commit without ever affecting the metadata we probe for.
+fn is_git_objects_path(path: &Path) -> bool {
+    path.components()
+        .collect::<Vec<_>>()
+        .windows(2)
+        .any(|w| w[0].as_os_str() == ".git" && w[1].as_os_str() == "objects")
+}
+
+/// Spawn a filesystem watcher covering each project directory recursively,
+/// so edits anywhere under the tree (not just at the root) are observed.
+/// `.git/objects` churns on every commit and is never relevant to the
+/// metadata we probe, so it's filtered out of the reported paths. Events
+/// are debounced on a background thread and delivered as deduplicated paths.
+fn spawn_watcher(projects: &[Project]) -> notify::Result<(RecommendedWatcher, Receiver<PathBuf>)> {
+    let (tx, rx) = channel::<PathBuf>();
+    let pending: Arc<Mutex<HashSet<PathBuf>>> = Arc::new(Mutex::new(HashSet::new()));
+
+    let pending_cb = Arc::clone(&pending);
+    let mut watcher = recommended_watcher(move |res: notify::Result<FsEvent>| {
+        if let Ok(event) = res {
+            let mut pending = pending_cb.lock().unwrap();
+            pending.extend(event.paths.into_iter().filter(|p| !is_git_objects_path(p)));
+        }
+    })?;
+
+    for project in projects {
+        watcher.watch(&project.path, RecursiveMode::Recursive).ok();
+    }
+
+    thread::spawn(move || loop {
+        thread::sleep(WATCH_DEBOUNCE);
+        let changed: Vec<PathBuf> = {
+            let mut pending = pending.lock().unwrap();
+            pending.drain().collect()
+        };
+        for path in changed {
+            if tx.send(path).is_err() {
+                return;
+            }
+        }
+    });
+
+    Ok((watcher, rx))
+}
+
 impl App {
-    fn new() -> Self {
-        let projects = scan_projects();
+    fn new(config: Config) -> Self {
+        let projects = scan_projects(&config);
         let mut list_state = ListState::default();
         if !projects.is_empty() {
             list_state.select(Some(0));
         }
+        let (watcher, watch_rx) = match spawn_watcher(&projects) {
+            Ok((watcher, rx)) => (Some(watcher), Some(rx)),
+            Err(e) => {
+                eprintln!("Failed to start filesystem watcher ({e}); continuing without live refresh");
+                (None, None)
+            }
+        };
+        let pending_count = projects.len();
+        let probe_rx = spawn_probe_tasks(&projects, config.clone());
+        let (detail_tx, detail_rx) = channel();
         Self {
+            config,
             projects,
             list_state,
             searching: false,
             filter: String::new(),
             quit: false,
+            _watcher: watcher,
+            watch_rx,
+            preview_visible: false,
+            preview_scroll: 0,
+            preview_cache: None,
+            sort_mode: SortMode::Recent,
+            probe_rx,
+            pending_count,
+            start_time: Instant::now(),
+            detail_tx,
+            detail_rx,
+        }
+    }
+
+    fn cycle_sort(&mut self) {
+        self.sort_mode = self.sort_mode.next();
+    }
+
+    /// Drain completed probe tasks, filling in each project's git/mtime/
+    /// config metadata as it arrives.
+    fn drain_probe_results(&mut self) {
+        while let Ok(result) = self.probe_rx.try_recv() {
+            if let Some(project) = self.projects.iter_mut().find(|p| p.path == result.path) {
+                project.git_branch = result.git_branch;
+                project.git_dirty = result.git_dirty;
+                project.modified = result.modified;
+                project.config_labels = result.config_labels;
+                project.has_doc = result.has_doc;
+                project.pending = false;
+                self.pending_count = self.pending_count.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Drain completed background detail-footer computations, filling in
+    /// the cache on whichever project they were computed for.
+    fn drain_detail_results(&mut self) {
+        while let Ok(result) = self.detail_rx.try_recv() {
+            if let Some(project) = self.projects.iter_mut().find(|p| p.path == result.path) {
+                project.detail = Some(result.detail);
+                project.detail_pending = false;
+            }
+        }
+    }
+
+    /// Current spinner glyph for rows still awaiting their probe result.
+    fn spinner_glyph(&self) -> char {
+        let elapsed_ms = self.start_time.elapsed().as_millis();
+        let frame = (elapsed_ms / SPINNER_FRAME_MS) as usize % SPINNER_FRAMES.len();
+        SPINNER_FRAMES[frame]
+    }
+
+    /// Recompute the highlighted preview for the selected project if the
+    /// cache is missing or stale (keyed by path + mtime). Cheap no-op
+    /// otherwise, so it's safe to call on every frame.
+    fn ensure_preview_cache(&mut self) {
+        if !self.preview_visible {
+            return;
+        }
+        let Some(project) = self.selected_project() else {
+            self.preview_cache = None;
+            return;
+        };
+        let Some(path) = find_preview_path(project, &self.config) else {
+            self.preview_cache = None;
+            return;
+        };
+        let mtime = fs::metadata(&path).ok().and_then(|m| m.modified().ok());
+
+        let stale = match &self.preview_cache {
+            Some(cache) => cache.path != path || cache.mtime != mtime,
+            None => true,
+        };
+        if stale {
+            let lines = render_preview(&path);
+            self.preview_cache = Some(PreviewCache { path, mtime, lines });
+            self.preview_scroll = 0;
         }
     }
 
+    /// Re-run the git/mtime/config probe for whichever project owns
+    /// `changed_path`, rather than rescanning everything.
+    fn refresh_project(&mut self, changed_path: &Path) {
+        if let Some(project) = self.projects.iter_mut().find(|p| changed_path.starts_with(&p.path)) {
+            let is_git = project.path.join(".git").exists();
+            let (git_branch, git_dirty, modified, config_labels) = probe_project(&project.path, is_git);
+            project.git_branch = git_branch;
+            project.git_dirty = git_dirty;
+            project.modified = modified;
+            project.config_labels = config_labels;
+            // The detail footer's stats (commit subject, ahead/behind, ...)
+            // may now be stale too; drop the cache so the next render recomputes it.
+            project.detail = None;
+        }
+    }
+
+    /// Kick off a background computation of the detail-footer stats for the
+    /// selected project if it doesn't have one cached or in flight yet. A
+    /// no-op while the result is cached on the `Project` itself (until
+    /// `refresh_project` clears it); runs off the render path so a large
+    /// `dir_size` walk or slow `git` calls never stall the UI.
+    fn ensure_detail_cache(&mut self) {
+        let Some(index) = self.selected_project_index() else { return };
+        let project = &mut self.projects[index];
+        if project.detail.is_none() && !project.detail_pending {
+            project.detail_pending = true;
+            spawn_detail_task(project.path.clone(), self.detail_tx.clone());
+        }
+    }
+
+    /// Projects matching the current filter. Matches against name, source,
+    /// and config labels, though only name match ranges are surfaced for
+    /// highlighting. Composes with `sort`: while searching, the fuzzy score
+    /// ranks first and the active `SortMode` breaks ties; otherwise the
+    /// `SortMode` alone orders the list.
     fn filtered_indices(&self) -> Vec<usize> {
-        let query = self.filter.to_lowercase();
-        self.projects
-            .iter()
-            .enumerate()
-            .filter(|(_, p)| query.is_empty() || p.name.to_lowercase().contains(&query))
-            .map(|(i, _)| i)
-            .collect()
+        let mut matches: Vec<(usize, Option<i32>)> = if self.filter.is_empty() {
+            (0..self.projects.len()).map(|i| (i, None)).collect()
+        } else {
+            self.projects
+                .iter()
+                .enumerate()
+                .filter_map(|(i, p)| {
+                    let name_score = fuzzy_match(&self.filter, &p.name).map(|m| m.score);
+                    let source_score = fuzzy_match(&self.filter, &p.source).map(|m| m.score);
+                    let label_score = p.config_labels
+                        .iter()
+                        .filter_map(|l| fuzzy_match(&self.filter, l).map(|m| m.score))
+                        .max();
+                    let best = [name_score, source_score, label_score].into_iter().flatten().max()?;
+                    Some((i, Some(best)))
+                })
+                .collect()
+        };
+
+        matches.sort_by(|(ia, sa), (ib, sb)| sb.cmp(sa).then_with(|| self.sort(*ia, *ib)));
+
+        matches.into_iter().map(|(i, _)| i).collect()
+    }
+
+    /// Compare two projects (by index) according to the active `SortMode`.
+    fn sort(&self, a: usize, b: usize) -> std::cmp::Ordering {
+        let pa = &self.projects[a];
+        let pb = &self.projects[b];
+        // Pending projects haven't had their modified/dirty state probed yet;
+        // keep them appended after resolved ones rather than letting each
+        // `None` -> `Some` transition reshuffle them mid-scan (`filtered_indices`
+        // relies on a stable sort, so ties here preserve scan order).
+        pa.pending.cmp(&pb.pending).then_with(|| match self.sort_mode {
+            SortMode::Recent => pb.modified.cmp(&pa.modified),
+            SortMode::Name => natural_cmp(&pa.name, &pb.name),
+            SortMode::Source => pa.source.cmp(&pb.source).then_with(|| natural_cmp(&pa.name, &pb.name)),
+            SortMode::Dirty => pb.git_dirty.cmp(&pa.git_dirty).then_with(|| pb.modified.cmp(&pa.modified)),
+        })
     }
 
     fn move_selection(&mut self, delta: i32) {
@@ -222,11 +863,14 @@ impl App {
         self.list_state.select(Some(new));
     }
 
-    fn selected_project(&self) -> Option<&Project> {
+    fn selected_project_index(&self) -> Option<usize> {
         let filtered = self.filtered_indices();
         let selected = self.list_state.selected()?;
-        let index = *filtered.get(selected)?;
-        self.projects.get(index)
+        filtered.get(selected).copied()
+    }
+
+    fn selected_project(&self) -> Option<&Project> {
+        self.projects.get(self.selected_project_index()?)
     }
 
     fn launch_claude(&self) {
@@ -250,6 +894,21 @@ impl App {
         }
     }
 
+    fn toggle_preview(&mut self) {
+        self.preview_visible = !self.preview_visible;
+        if !self.preview_visible {
+            self.preview_cache = None;
+        }
+    }
+
+    fn scroll_preview(&mut self, delta: i32) {
+        if delta > 0 {
+            self.preview_scroll = self.preview_scroll.saturating_add(delta as u16);
+        } else {
+            self.preview_scroll = self.preview_scroll.saturating_sub((-delta) as u16);
+        }
+    }
+
     fn open_finder(&self) {
         if let Some(project) = self.selected_project() {
             Command::new("open").arg(&project.path).spawn().ok();
@@ -258,15 +917,25 @@ impl App {
 
     fn open_doc(&self) {
         if let Some(project) = self.selected_project() {
-            if let Some(doc_path) = find_obsidian_doc(&project.name) {
+            if let Some(doc_path) = find_obsidian_doc(&project.name, &self.config) {
                 // Get the filename without .md extension for the Obsidian URI
                 let file_stem = doc_path
                     .file_stem()
                     .unwrap_or_default()
                     .to_string_lossy();
+                let doc_path_encoded = self
+                    .config
+                    .obsidian
+                    .doc_path
+                    .split('/')
+                    .map(percent_encode)
+                    .collect::<Vec<_>>()
+                    .join("%2F");
                 let uri = format!(
-                    "obsidian://open?vault=NV&file=Personal%2FApp%2F{}",
-                    file_stem.replace(' ', "%20")
+                    "obsidian://open?vault={}&file={}%2F{}",
+                    percent_encode(&self.config.obsidian.vault),
+                    doc_path_encoded,
+                    percent_encode(&file_stem)
                 );
                 Command::new("open").arg(uri).spawn().ok();
             }
@@ -275,9 +944,10 @@ impl App {
 }
 
 fn draw(frame: &mut Frame, app: &App) {
-    let [header_area, main_area, footer_area] = Layout::vertical([
+    let [header_area, main_area, detail_area, footer_area] = Layout::vertical([
         Constraint::Length(3),
         Constraint::Min(1),
+        Constraint::Length(1),
         Constraint::Length(3),
     ])
     .areas(frame.area());
@@ -290,13 +960,24 @@ fn draw(frame: &mut Frame, app: &App) {
             Span::styled("▌", Style::default().fg(Color::Yellow)),
         ])
     } else {
-        Line::from(vec![
+        let mut header_spans = vec![
             Span::styled(" claude-tui ", Style::default().fg(Color::Cyan).bold()),
             Span::styled(
-                format!(" {} projects", app.filtered_indices().len()),
+                format!(" {} projects ", app.filtered_indices().len()),
                 Style::default().fg(Color::DarkGray),
             ),
-        ])
+            Span::styled(
+                format!("· sort: {} ", app.sort_mode.indicator()),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ];
+        if app.pending_count > 0 {
+            header_spans.push(Span::styled(
+                format!("· {} {} scanning ", app.spinner_glyph(), app.pending_count),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        Line::from(header_spans)
     };
 
     let header = Paragraph::new(title).block(
@@ -306,9 +987,19 @@ fn draw(frame: &mut Frame, app: &App) {
     );
     frame.render_widget(header, header_area);
 
+    let (list_area, preview_area) = if app.preview_visible {
+        let [list_area, preview_area] =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .areas(main_area);
+        (list_area, Some(preview_area))
+    } else {
+        (main_area, None)
+    };
+
     // Project list
     let filtered = app.filtered_indices();
-    let list_width = main_area.width as usize;
+    let list_width = list_area.width as usize;
+    let spinner = app.spinner_glyph();
     let items: Vec<ListItem> = filtered
         .iter()
         .map(|&i| {
@@ -317,7 +1008,8 @@ fn draw(frame: &mut Frame, app: &App) {
 
             // Build the left-side content to measure its width
             let source_col = format!(" {:>10} ", p.source);
-            let mut left_len = source_col.len() + p.name.len();
+            let spinner_col = if p.pending { format!("{} ", spinner) } else { String::new() };
+            let mut left_len = source_col.len() + spinner_col.len() + p.name.len();
 
             let branch_str = match (&p.git_branch, p.git_dirty) {
                 (Some(b), true) => { let s = format!("  {}*", b); left_len += s.len(); Some(s) }
@@ -337,10 +1029,34 @@ fn draw(frame: &mut Frame, app: &App) {
 
             let padding = list_width.saturating_sub(left_len + time_str.len() + 6);
 
-            let mut spans = vec![
-                Span::styled(source_col, Style::default().fg(Color::DarkGray)),
-                Span::styled(&p.name, Style::default().fg(Color::White)),
-            ];
+            let mut spans = vec![Span::styled(source_col, Style::default().fg(Color::DarkGray))];
+            if p.pending {
+                spans.push(Span::styled(spinner_col.clone(), Style::default().fg(Color::Yellow)));
+            }
+
+            let name_ranges = if app.filter.is_empty() {
+                Vec::new()
+            } else {
+                fuzzy_match(&app.filter, &p.name).map(|m| m.ranges).unwrap_or_default()
+            };
+            if name_ranges.is_empty() {
+                spans.push(Span::styled(p.name.clone(), Style::default().fg(Color::White)));
+            } else {
+                let mut last = 0;
+                for (start, end) in name_ranges {
+                    if start > last {
+                        spans.push(Span::styled(p.name[last..start].to_string(), Style::default().fg(Color::White)));
+                    }
+                    spans.push(Span::styled(
+                        p.name[start..end].to_string(),
+                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                    ));
+                    last = end;
+                }
+                if last < p.name.len() {
+                    spans.push(Span::styled(p.name[last..].to_string(), Style::default().fg(Color::White)));
+                }
+            }
 
             if let Some(ref b) = branch_str {
                 spans.push(Span::styled(b.clone(), Style::default().fg(Color::Magenta)));
@@ -372,7 +1088,59 @@ fn draw(frame: &mut Frame, app: &App) {
         )
         .highlight_symbol("▸ ");
 
-    frame.render_stateful_widget(list, main_area, &mut app.list_state.clone());
+    frame.render_stateful_widget(list, list_area, &mut app.list_state.clone());
+
+    if let Some(preview_area) = preview_area {
+        let block = Block::default()
+            .borders(Borders::LEFT)
+            .border_style(Style::default().fg(Color::DarkGray))
+            .padding(Padding::new(1, 1, 0, 0));
+        let paragraph = match &app.preview_cache {
+            Some(cache) => Paragraph::new(cache.lines.clone())
+                .block(block)
+                .scroll((app.preview_scroll, 0)),
+            None => Paragraph::new(Line::from(Span::styled(
+                "(no CLAUDE.md, README.md, or doc found)",
+                Style::default().fg(Color::DarkGray),
+            )))
+            .block(block),
+        };
+        frame.render_widget(paragraph, preview_area);
+    }
+
+    // Detail footer for the selected project
+    let detail_line = match app.selected_project().and_then(|p| p.detail.as_ref()) {
+        Some(detail) => {
+            let mut spans = vec![Span::styled(
+                format!(" {} ", detail.absolute_path),
+                Style::default().fg(Color::DarkGray),
+            )];
+            if let Some(ref subject) = detail.last_commit_subject {
+                spans.push(Span::styled("· ", Style::default().fg(Color::DarkGray)));
+                spans.push(Span::styled(subject.clone(), Style::default().fg(Color::White)));
+                spans.push(Span::raw(" "));
+            }
+            if let Some((ahead, behind)) = detail.ahead_behind {
+                spans.push(Span::styled(
+                    format!("· ↑{} ↓{} ", ahead, behind),
+                    Style::default().fg(Color::Magenta),
+                ));
+            }
+            if detail.dirty_count > 0 {
+                spans.push(Span::styled(
+                    format!("· {} dirty ", detail.dirty_count),
+                    Style::default().fg(Color::Yellow),
+                ));
+            }
+            spans.push(Span::styled(
+                format!("· {}", format_size(detail.total_size)),
+                Style::default().fg(Color::DarkGray),
+            ));
+            Line::from(spans)
+        }
+        None => Line::from(""),
+    };
+    frame.render_widget(Paragraph::new(detail_line), detail_area);
 
     // Footer
     let help = if app.searching {
@@ -394,6 +1162,10 @@ fn draw(frame: &mut Frame, app: &App) {
             Span::styled("finder  ", Style::default().fg(Color::DarkGray)),
             Span::styled("d ", Style::default().fg(Color::Cyan)),
             Span::styled("docs  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("p ", Style::default().fg(Color::Cyan)),
+            Span::styled("preview  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("s ", Style::default().fg(Color::Cyan)),
+            Span::styled("sort  ", Style::default().fg(Color::DarkGray)),
             Span::styled("/ ", Style::default().fg(Color::Cyan)),
             Span::styled("search  ", Style::default().fg(Color::DarkGray)),
             Span::styled("q ", Style::default().fg(Color::Cyan)),
@@ -415,11 +1187,27 @@ fn main() -> io::Result<()> {
 
     let backend = ratatui::backend::CrosstermBackend::new(stdout());
     let mut terminal = Terminal::new(backend)?;
-    let mut app = App::new();
+    let mut app = App::new(Config::load());
 
     while !app.quit {
+        app.drain_probe_results();
+        app.drain_detail_results();
+        app.ensure_preview_cache();
+        app.ensure_detail_cache();
         terminal.draw(|frame| draw(frame, &app))?;
 
+        if let Some(watch_rx) = &app.watch_rx {
+            while let Ok(changed_path) = watch_rx.try_recv() {
+                app.refresh_project(&changed_path);
+            }
+        }
+
+        // Keep the spinner animating for pending rows even with no input.
+        let poll_timeout = if app.pending_count > 0 { Duration::from_millis(80) } else { Duration::from_millis(100) };
+        if !event::poll(poll_timeout)? {
+            continue;
+        }
+
         if let Event::Key(key) = event::read()? {
             if key.kind != KeyEventKind::Press {
                 continue;
@@ -449,13 +1237,19 @@ fn main() -> io::Result<()> {
                     _ => {}
                 }
             } else {
+                let keys = app.config.keys.clone();
                 match key.code {
-                    KeyCode::Char('q') => app.quit = true,
-                    KeyCode::Char('f') => app.open_finder(),
-                    KeyCode::Char('d') => app.open_doc(),
-                    KeyCode::Char('/') => app.searching = true,
+                    KeyCode::Char(c) if c == keys.quit => app.quit = true,
+                    KeyCode::Char(c) if c == keys.finder => app.open_finder(),
+                    KeyCode::Char(c) if c == keys.docs => app.open_doc(),
+                    KeyCode::Char(c) if Some(c) == keys.launch => app.launch_claude(),
+                    KeyCode::Char(c) if c == keys.search => app.searching = true,
+                    KeyCode::Char('p') => app.toggle_preview(),
+                    KeyCode::Char('s') => app.cycle_sort(),
                     KeyCode::Up | KeyCode::Char('k') => app.move_selection(-1),
                     KeyCode::Down | KeyCode::Char('j') => app.move_selection(1),
+                    KeyCode::PageUp => app.scroll_preview(-10),
+                    KeyCode::PageDown => app.scroll_preview(10),
                     KeyCode::Enter => app.launch_claude(),
                     _ => {}
                 }